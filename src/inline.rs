@@ -0,0 +1,139 @@
+//! A bump arena with its storage held inline rather than borrowed or
+//! fetched from an `AllocRef`, for targets with no heap at all.
+
+use core::cell::{Cell, UnsafeCell};
+use core::mem;
+use core::ptr::NonNull;
+
+use alloc_wg::alloc::{AllocRef, BuildAllocRef, DeallocRef, NonZeroLayout};
+
+use crate::AllocErr;
+
+/// A fixed-capacity bump arena whose `N`-byte backing storage lives inline
+/// in the struct, so it needs no `AllocRef` and no heap. Unlike
+/// [`BumpAlloc::from_slice`](crate::BumpAlloc::from_slice), there's no
+/// borrowed buffer to keep alive — the arena owns its bytes outright.
+pub struct Bump<const N: usize> {
+    storage: UnsafeCell<[u8; N]>,
+    // Offset from the start of `storage`, not an absolute pointer: `Bump`
+    // may be moved between allocations, so the cursor can't cache an
+    // address into `storage`.
+    ptr: Cell<usize>,
+}
+
+impl<const N: usize> Default for Bump<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Bump<N> {
+    pub const fn new() -> Self {
+        Self {
+            storage: UnsafeCell::new([0; N]),
+            ptr: Cell::new(N),
+        }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_t<T>(&self, val: T) -> Result<&mut T, <&Self as AllocRef>::Error> {
+        assert!(mem::size_of::<T>() > 0);
+
+        unsafe {
+            let layout = NonZeroLayout::new_unchecked::<T>();
+
+            let ptr = self.alloc(layout)?;
+            let ptr = ptr.cast::<T>().as_ptr();
+
+            core::ptr::write(ptr, val);
+            Ok(&mut *ptr)
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.ptr.set(N);
+    }
+}
+
+impl<const N: usize> BuildAllocRef for &Bump<N> {
+    type Ref = Self;
+
+    unsafe fn build_alloc_ref(
+        &self,
+        _ptr: NonNull<u8>,
+        _layout: Option<NonZeroLayout>,
+    ) -> Self::Ref {
+        self
+    }
+}
+
+impl<const N: usize> DeallocRef for &Bump<N> {
+    type BuildAlloc = Self;
+
+    fn get_build_alloc(&self) -> Self::BuildAlloc {
+        self
+    }
+
+    unsafe fn dealloc(&self, _ptr: NonNull<u8>, _layout: NonZeroLayout) {}
+}
+
+impl<const N: usize> AllocRef for &Bump<N> {
+    type Error = AllocErr;
+
+    fn alloc(&self, layout: NonZeroLayout) -> Result<NonNull<u8>, Self::Error> {
+        let base = self.storage.get() as usize;
+        let ptr = base + self.ptr.get();
+
+        let new_ptr = ptr.checked_sub(layout.size().get()).ok_or(AllocErr)?;
+
+        // Round down to the requested alignment.
+        let new_ptr = new_ptr & !(layout.align().get() - 1);
+
+        if new_ptr < base {
+            // Not enough capacity.
+            return Err(AllocErr);
+        }
+
+        self.ptr.set(new_ptr - base);
+        Ok(unsafe { NonNull::new_unchecked(new_ptr as *mut u8) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Bump;
+
+    #[test]
+    fn inline_bump_alloc() {
+        let bump = Bump::<{ core::mem::size_of::<f32>() * 2 }>::new();
+
+        let stack_a = 1.2f32;
+        let alloc_a = bump.alloc_t(stack_a.clone()).unwrap();
+        assert_eq!(stack_a, *alloc_a);
+
+        let stack_b = 2.4f32;
+        let alloc_b = bump.alloc_t(stack_b.clone()).unwrap();
+        assert_eq!(stack_b, *alloc_b);
+    }
+
+    #[test]
+    fn inline_bump_exhausted() {
+        let bump = Bump::<4>::new();
+
+        assert!(bump.alloc_t(1.2f32).is_ok());
+        assert!(bump.alloc_t(2.4f32).is_err());
+    }
+
+    #[test]
+    fn inline_bump_reset() {
+        let mut bump = Bump::<4>::new();
+
+        for idx in 0..=2 {
+            bump.reset();
+
+            let new_stack: f32 = idx as f32;
+            let new_alloc = bump.alloc_t(new_stack.clone()).unwrap();
+            assert_eq!(new_stack, *new_alloc);
+        }
+    }
+}
@@ -7,6 +7,12 @@ use core::ptr::{self, NonNull};
 
 use alloc_wg::alloc::{AllocRef, BuildAllocRef, DeallocRef, Global, NonZeroLayout, ReallocRef};
 
+mod inline;
+mod sync;
+
+pub use inline::Bump;
+pub use sync::SyncBumpAlloc;
+
 pub enum BumpAllocErr<A: AllocRef> {
     ZeroCapacity,
 
@@ -16,11 +22,35 @@ pub enum BumpAllocErr<A: AllocRef> {
     },
 }
 
-#[derive(Clone, Debug)]
-pub struct BumpAlloc<A: DeallocRef = Global> {
+/// A single backing region, either the arena's original chunk or one of the
+/// chunks grown into afterwards.
+#[derive(Clone, Copy, Debug)]
+struct Chunk {
     data: NonNull<u8>,
     layout: NonZeroLayout,
+}
+
+/// A retired chunk, kept around only so its memory can be freed once it is
+/// no longer the current chunk.
+struct ChunkFooter {
+    chunk: Chunk,
+    prev: Option<NonNull<ChunkFooter>>,
+}
+
+// Intentionally not `Clone`: `BumpAlloc` owns its chunk chain, so a clone
+// would share `base`/`current`/`chunks` pointers with the original and
+// double-free them once both were dropped.
+#[derive(Debug)]
+pub struct BumpAlloc<A: DeallocRef = Global> {
+    /// The chunk this arena was first created with. Never freed until the
+    /// arena itself is dropped, and what `reset` rewinds back to.
+    base: Chunk,
+    /// The chunk currently being bumped into.
+    current: Cell<Chunk>,
     ptr: Cell<NonNull<u8>>,
+    /// Chunks older than `current` (and newer than `base`), linked from
+    /// newest to oldest so they can be walked and freed in order.
+    chunks: Cell<Option<NonNull<ChunkFooter>>>,
     build_alloc: A::BuildAlloc,
 }
 
@@ -49,13 +79,14 @@ impl<A: AllocRef> BumpAlloc<A> {
             .alloc(layout)
             .map_err(|inner| BumpAllocErr::AllocError { layout, inner })?;
 
-        let new_ptr = data.clone().as_ptr() as usize;
-        let new_ptr = new_ptr + layout.size().get();
+        let base = Chunk { data, layout };
+        let new_ptr = base.data.as_ptr() as usize + base.layout.size().get();
 
         Ok(Self {
-            data,
-            layout,
+            base,
+            current: Cell::new(base),
             ptr: Cell::new(unsafe { NonNull::new_unchecked(new_ptr as *mut u8) }),
+            chunks: Cell::new(None),
             build_alloc: a.get_build_alloc(),
         })
     }
@@ -75,17 +106,401 @@ impl<A: AllocRef> BumpAlloc<A> {
         }
     }
 
+    /// Like [`alloc_t`](Self::alloc_t), but builds `T` in place from a
+    /// closure instead of taking it by value. This avoids the stack copy
+    /// that `alloc_t` forces on the caller for large `T`.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_with<T, F: FnOnce() -> T>(&self, f: F) -> Result<&mut T, <&Self as AllocRef>::Error> {
+        assert!(mem::size_of::<T>() > 0);
+
+        unsafe {
+            let layout = NonZeroLayout::new_unchecked::<T>();
+
+            let ptr = self.alloc(layout)?;
+            let ptr = ptr.cast::<T>().as_ptr();
+
+            ptr::write(ptr, f());
+            Ok(&mut *ptr)
+        }
+    }
+
+    /// Like [`alloc_with`](Self::alloc_with), but for a fallible initializer.
+    /// Distinguishes arena exhaustion from initializer failure so the caller
+    /// doesn't have to build `T` up front just to find out it would have
+    /// failed to construct.
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc_try_with<T, E, F: FnOnce() -> Result<T, E>>(
+        &self,
+        f: F,
+    ) -> Result<&mut T, AllocOrInitError<E>> {
+        assert!(mem::size_of::<T>() > 0);
+
+        unsafe {
+            let layout = NonZeroLayout::new_unchecked::<T>();
+
+            let ptr = self.alloc(layout).map_err(AllocOrInitError::Alloc)?;
+            let ptr = ptr.cast::<T>().as_ptr();
+
+            match f() {
+                Ok(val) => {
+                    ptr::write(ptr, val);
+                    Ok(&mut *ptr)
+                }
+                Err(err) => Err(AllocOrInitError::Init(err)),
+            }
+        }
+    }
+
+    /// Copies `src` into the arena and returns a mutable reference to the
+    /// copy.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_copy<T: Copy>(&self, src: &[T]) -> Result<&mut [T], AllocErr> {
+        let len = src.len();
+
+        if len == 0 || mem::size_of::<T>() == 0 {
+            return Ok(unsafe {
+                core::slice::from_raw_parts_mut(NonNull::<T>::dangling().as_ptr(), len)
+            });
+        }
+
+        unsafe {
+            let layout = NonZeroLayout::from_size_align_unchecked(
+                NonZeroUsize::new_unchecked(len * mem::size_of::<T>()),
+                NonZeroUsize::new_unchecked(mem::align_of::<T>()),
+            );
+
+            let ptr = self.alloc(layout)?.cast::<T>().as_ptr();
+            ptr::copy_nonoverlapping(src.as_ptr(), ptr, len);
+
+            Ok(core::slice::from_raw_parts_mut(ptr, len))
+        }
+    }
+
+    /// Copies `s` into the arena and returns a mutable reference to the copy.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_str(&self, s: &str) -> Result<&mut str, AllocErr> {
+        let bytes = self.alloc_slice_copy(s.as_bytes())?;
+
+        // Safe because `bytes` is a fresh copy of `s`'s own (valid) bytes.
+        Ok(unsafe { core::str::from_utf8_unchecked_mut(bytes) })
+    }
+
+    /// Allocates a `len`-element slice, filling each element in place with
+    /// `f(index)`.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill_with<T>(
+        &self,
+        len: usize,
+        mut f: impl FnMut(usize) -> T,
+    ) -> Result<&mut [T], AllocErr> {
+        if len == 0 || mem::size_of::<T>() == 0 {
+            return Ok(unsafe {
+                core::slice::from_raw_parts_mut(NonNull::<T>::dangling().as_ptr(), len)
+            });
+        }
+
+        unsafe {
+            let layout = NonZeroLayout::from_size_align_unchecked(
+                NonZeroUsize::new_unchecked(len * mem::size_of::<T>()),
+                NonZeroUsize::new_unchecked(mem::align_of::<T>()),
+            );
+
+            let ptr = self.alloc(layout)?.cast::<T>().as_ptr();
+            for idx in 0..len {
+                ptr::write(ptr.add(idx), f(idx));
+            }
+
+            Ok(core::slice::from_raw_parts_mut(ptr, len))
+        }
+    }
+
+    /// Allocates a new chunk sized to fit `required` (doubling the previous
+    /// chunk's size where that's already enough), retires the current chunk
+    /// onto the `chunks` list unless it is `base`, and makes the new chunk
+    /// current.
+    fn grow(&self, required: NonZeroLayout) -> Result<(), AllocErr> {
+        let current = self.current.get();
+
+        let prev_size = current.layout.size().get();
+        let new_size = prev_size
+            .checked_mul(2)
+            .unwrap_or(prev_size)
+            .max(required.size().get());
+
+        let new_layout = unsafe {
+            NonZeroLayout::from_size_align_unchecked(
+                NonZeroUsize::new_unchecked(new_size),
+                NonZeroUsize::new_unchecked(1),
+            )
+        };
+
+        let a = unsafe {
+            self.build_alloc
+                .build_alloc_ref(current.data, Some(current.layout))
+        };
+        let new_data = a.alloc(new_layout).map_err(|_| AllocErr)?;
+
+        // `base` is freed directly by `Drop`, not through the `chunks`
+        // list, so only retire `current` onto that list when it isn't
+        // `base` itself — otherwise it would end up freed twice.
+        if current.data != self.base.data {
+            let footer_layout = unsafe { NonZeroLayout::new_unchecked::<ChunkFooter>() };
+            let footer_ptr = match a.alloc(footer_layout) {
+                Ok(ptr) => ptr.cast::<ChunkFooter>(),
+                Err(_) => {
+                    // Don't leak the chunk just allocated above.
+                    unsafe { a.dealloc(new_data, new_layout) };
+                    return Err(AllocErr);
+                }
+            };
+
+            unsafe {
+                ptr::write(
+                    footer_ptr.as_ptr(),
+                    ChunkFooter {
+                        chunk: current,
+                        prev: self.chunks.get(),
+                    },
+                );
+            }
+
+            self.chunks.set(Some(footer_ptr));
+        }
+
+        self.current.set(Chunk {
+            data: new_data,
+            layout: new_layout,
+        });
+
+        let new_ptr = new_data.as_ptr() as usize + new_layout.size().get();
+        self.ptr
+            .set(unsafe { NonNull::new_unchecked(new_ptr as *mut u8) });
+
+        Ok(())
+    }
+}
+
+/// The "allocator" behind [`BumpAlloc::from_slice`]: it owns nothing, so
+/// allocating, deallocating and growing through it are all no-ops (`alloc`
+/// always fails, which simply means the arena can't grow past the borrowed
+/// slice and returns `AllocErr` once that's exhausted, exactly like a fixed
+/// `no_std` target with no heap would want).
+///
+/// The private field keeps this unconstructible outside the crate: its
+/// `alloc` is unconditionally fallible, so a caller passing it to
+/// [`BumpAlloc::with_capacity_in`] would hit that constructor's
+/// infallible-allocator assumption and panic. [`BumpAlloc::from_slice`] is
+/// the only sanctioned way to get a `BumpAlloc<Unmanaged>`.
+#[derive(Clone, Copy, Debug)]
+pub struct Unmanaged(());
+
+impl DeallocRef for Unmanaged {
+    type BuildAlloc = Unmanaged;
+
+    fn get_build_alloc(&self) -> Self::BuildAlloc {
+        Unmanaged(())
+    }
+
+    unsafe fn dealloc(&self, _ptr: NonNull<u8>, _layout: NonZeroLayout) {}
+}
+
+impl BuildAllocRef for Unmanaged {
+    type Ref = Unmanaged;
+
+    unsafe fn build_alloc_ref(
+        &self,
+        _ptr: NonNull<u8>,
+        _layout: Option<NonZeroLayout>,
+    ) -> Self::Ref {
+        Unmanaged(())
+    }
+}
+
+impl AllocRef for Unmanaged {
+    type Error = AllocErr;
+
+    fn alloc(&self, _layout: NonZeroLayout) -> Result<NonNull<u8>, Self::Error> {
+        Err(AllocErr)
+    }
+}
+
+impl BumpAlloc<Unmanaged> {
+    /// Builds an arena directly over caller-provided memory instead of
+    /// requesting its own region from an `AllocRef`. Useful on `no_std`
+    /// targets with no heap at all; the arena never grows past `buf` and
+    /// `Drop` is a no-op, since the crate never owns this memory.
+    pub fn from_slice(buf: &mut [u8]) -> Self {
+        assert!(!buf.is_empty(), "zero capacity");
+
+        let layout = unsafe {
+            NonZeroLayout::from_size_align_unchecked(
+                NonZeroUsize::new_unchecked(buf.len()),
+                NonZeroUsize::new_unchecked(1),
+            )
+        };
+        let data = unsafe { NonNull::new_unchecked(buf.as_mut_ptr()) };
+
+        let base = Chunk { data, layout };
+        let new_ptr = data.as_ptr() as usize + layout.size().get();
+
+        Self {
+            base,
+            current: Cell::new(base),
+            ptr: Cell::new(unsafe { NonNull::new_unchecked(new_ptr as *mut u8) }),
+            chunks: Cell::new(None),
+            build_alloc: Unmanaged(()),
+        }
+    }
+}
+
+/// A snapshot of a [`BumpAlloc`]'s bump pointer, captured by
+/// [`BumpAlloc::checkpoint`] and later restored by
+/// [`BumpAlloc::reset_to`]. Allocations made after the checkpoint was taken
+/// are all at lower addresses in this downward arena, so restoring one frees
+/// them in bulk; references into that range are invalidated.
+#[derive(Clone, Copy, Debug)]
+pub struct Checkpoint {
+    chunk: Chunk,
+    ptr: NonNull<u8>,
+}
+
+/// An RAII guard that [`reset_to`](BumpAlloc::reset_to)s its arena back to a
+/// checkpoint taken at creation, reclaiming everything allocated during the
+/// scope once it's dropped. See [`BumpAlloc::scope`].
+pub struct Scope<'a, A: DeallocRef = Global> {
+    bump: &'a BumpAlloc<A>,
+    checkpoint: Checkpoint,
+}
+
+impl<'a, A: DeallocRef> core::ops::Deref for Scope<'a, A> {
+    type Target = BumpAlloc<A>;
+
+    fn deref(&self) -> &Self::Target {
+        self.bump
+    }
+}
+
+impl<'a, A: DeallocRef> Drop for Scope<'a, A> {
+    fn drop(&mut self) {
+        unsafe {
+            self.bump.reset_to(self.checkpoint);
+        }
+    }
+}
+
+impl<A: DeallocRef> BumpAlloc<A> {
     pub fn reset(&mut self) {
         unsafe {
             self.reset_unchecked();
         }
     }
 
+    /// Frees every chunk but `base` and rewinds the bump pointer to the top
+    /// of `base`.
     pub unsafe fn reset_unchecked(&self) {
-        let new_ptr = self.data.as_ptr() as usize;
-        let new_ptr = new_ptr + self.layout.size().get();
+        let mut node = self.chunks.take();
+        while let Some(footer_ptr) = node {
+            let footer = ptr::read(footer_ptr.as_ptr());
+            self.free_chunk(footer.chunk);
+            self.free_footer(footer_ptr);
+            node = footer.prev;
+        }
+
+        // The current chunk was never retired onto `chunks` (that only
+        // happens when a later `grow` supersedes it), so free it here too —
+        // unless it's `base`, which `Drop` frees itself.
+        let current = self.current.get();
+        if current.data != self.base.data {
+            self.free_chunk(current);
+        }
+
+        self.current.set(self.base);
+        let new_ptr = self.base.data.as_ptr() as usize + self.base.layout.size().get();
         self.ptr.set(NonNull::new_unchecked(new_ptr as *mut u8));
     }
+
+    /// Captures the current bump pointer, so allocations made from here on
+    /// can later be reclaimed in bulk with [`reset_to`](Self::reset_to).
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            chunk: self.current.get(),
+            ptr: self.ptr.get(),
+        }
+    }
+
+    /// Restores a checkpoint taken earlier via [`checkpoint`](Self::checkpoint),
+    /// freeing any chunks grown into since and rewinding the bump pointer.
+    /// References into the reclaimed range are invalidated by this call.
+    pub unsafe fn reset_to(&self, cp: Checkpoint) {
+        let current = self.current.get();
+
+        // The checkpoint's chunk is still the current one, so nothing was
+        // grown into since it was taken — there's nothing to free, and
+        // `cp.chunk` isn't in `chunks` to walk to in the first place.
+        if current.data != cp.chunk.data {
+            // `current` was never retired onto `chunks` (that only happens
+            // once a later `grow` supersedes it), so it has to be freed
+            // explicitly here rather than by the walk below.
+            self.free_chunk(current);
+
+            let mut node = self.chunks.get();
+            while let Some(footer_ptr) = node {
+                let footer = ptr::read(footer_ptr.as_ptr());
+                if footer.chunk.data == cp.chunk.data {
+                    // `cp.chunk` is becoming `current` again, not staying
+                    // retired, so drop its footer node from the list —
+                    // otherwise it ends up referenced as both `current` and
+                    // a retired footer, and a later `grow`/`reset` pair
+                    // would free it twice.
+                    self.free_footer(footer_ptr);
+                    node = footer.prev;
+                    break;
+                }
+
+                self.free_chunk(footer.chunk);
+                self.free_footer(footer_ptr);
+                node = footer.prev;
+            }
+
+            self.chunks.set(node);
+            self.current.set(cp.chunk);
+        }
+
+        self.ptr.set(cp.ptr);
+    }
+
+    /// Starts a [`Scope`] that rewinds this arena to its current state once
+    /// dropped, for the classic nested-frame arena pattern.
+    pub fn scope(&self) -> Scope<'_, A> {
+        Scope {
+            bump: self,
+            checkpoint: self.checkpoint(),
+        }
+    }
+
+    unsafe fn free_chunk(&self, chunk: Chunk) {
+        let a = self
+            .build_alloc
+            .build_alloc_ref(chunk.data, Some(chunk.layout));
+        a.dealloc(chunk.data, chunk.layout);
+    }
+
+    unsafe fn free_footer(&self, footer_ptr: NonNull<ChunkFooter>) {
+        let footer_layout = NonZeroLayout::new_unchecked::<ChunkFooter>();
+        let a = self
+            .build_alloc
+            .build_alloc_ref(footer_ptr.cast(), Some(footer_layout));
+        a.dealloc(footer_ptr.cast(), footer_layout);
+    }
+}
+
+impl<A: DeallocRef> Drop for BumpAlloc<A> {
+    fn drop(&mut self) {
+        unsafe {
+            self.reset_unchecked();
+            self.free_chunk(self.current.get());
+        }
+    }
 }
 
 impl<A: DeallocRef> BuildAllocRef for &BumpAlloc<A> {
@@ -113,39 +528,111 @@ impl<A: DeallocRef> DeallocRef for &BumpAlloc<A> {
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct AllocErr;
 
-impl<A: DeallocRef> AllocRef for &BumpAlloc<A> {
+/// The error returned by [`BumpAlloc::try_alloc_try_with`], distinguishing
+/// arena exhaustion from the initializer closure itself failing.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AllocOrInitError<E> {
+    Alloc(AllocErr),
+    Init(E),
+}
+
+impl<A: AllocRef> AllocRef for &BumpAlloc<A> {
     type Error = AllocErr;
 
     fn alloc(&self, layout: NonZeroLayout) -> Result<NonNull<u8>, Self::Error> {
+        if let Some(ptr) = self.try_alloc(layout) {
+            return Ok(ptr);
+        }
+
+        self.grow(layout)?;
+        self.try_alloc(layout).ok_or(AllocErr)
+    }
+}
+
+impl<A: AllocRef> BumpAlloc<A> {
+    /// Tries to bump-allocate `layout` out of the current chunk, without
+    /// growing the arena.
+    fn try_alloc(&self, layout: NonZeroLayout) -> Option<NonNull<u8>> {
+        let current = self.current.get();
+
         let ptr = self.ptr.get().as_ptr() as usize;
-        let new_ptr = ptr.checked_sub(layout.size().get()).ok_or(AllocErr)?;
+        let new_ptr = ptr.checked_sub(layout.size().get())?;
 
         // Round down to the requested alignment.
         let new_ptr = new_ptr & !(layout.align().get() - 1);
 
-        let start = self.data.as_ptr() as usize;
+        let start = current.data.as_ptr() as usize;
         if new_ptr < start {
-            // Not enough capacity
-            return Err(AllocErr);
+            // Not enough capacity in the current chunk.
+            return None;
         }
 
         self.ptr
             .set(unsafe { NonNull::new_unchecked(new_ptr as *mut u8) });
-        Ok(self.ptr.get())
+        Some(self.ptr.get())
     }
 }
 
-impl<A: DeallocRef> ReallocRef for &BumpAlloc<A> {
+impl<A: AllocRef> ReallocRef for &BumpAlloc<A> {
     unsafe fn realloc(
         &self,
-        _ptr: NonNull<u8>,
-        _old_layout: NonZeroLayout,
+        ptr: NonNull<u8>,
+        old_layout: NonZeroLayout,
         new_layout: NonZeroLayout,
     ) -> Result<NonNull<u8>, Self::Error> {
+        // Fast path: if `ptr` is exactly the last block handed out (i.e. it
+        // sits at the current bump pointer), resize it in place instead of
+        // falling back to `alloc`, which would leak the old block behind a
+        // fresh one on every `Vec`/`String` growth.
+        if ptr == self.ptr.get() {
+            if let Some(resized) = self.try_realloc_last(ptr, old_layout, new_layout) {
+                return Ok(resized);
+            }
+        }
+
         self.alloc(new_layout)
     }
 }
 
+impl<A: AllocRef> BumpAlloc<A> {
+    /// Resizes the last allocation (the block starting at `self.ptr`) in
+    /// place, returning `None` when a grow doesn't fit in the current chunk.
+    unsafe fn try_realloc_last(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: NonZeroLayout,
+        new_layout: NonZeroLayout,
+    ) -> Option<NonNull<u8>> {
+        let old_size = old_layout.size().get();
+        let new_size = new_layout.size().get();
+
+        if new_size <= old_size {
+            let new_ptr = ptr.as_ptr() as usize + (old_size - new_size);
+            let new_ptr = NonNull::new_unchecked(new_ptr as *mut u8);
+            // `new_ptr` overlaps `ptr`, so the leading bytes that survive
+            // the shrink have to be shifted up to it with `copy`, not
+            // `copy_nonoverlapping`.
+            ptr::copy(ptr.as_ptr(), new_ptr.as_ptr(), new_size);
+            self.ptr.set(new_ptr);
+            return Some(new_ptr);
+        }
+
+        let delta = new_size - old_size;
+        let new_ptr = (ptr.as_ptr() as usize).checked_sub(delta)?;
+        let new_ptr = new_ptr & !(new_layout.align().get() - 1);
+
+        let start = self.current.get().data.as_ptr() as usize;
+        if new_ptr < start {
+            return None;
+        }
+
+        let new_ptr = NonNull::new_unchecked(new_ptr as *mut u8);
+        ptr::copy(ptr.as_ptr(), new_ptr.as_ptr(), old_size);
+        self.ptr.set(new_ptr);
+        Some(new_ptr)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::BumpAlloc;
@@ -212,16 +699,204 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn bump_invalid_alloc_nomemory() {
+    fn bump_string_realloc_grows_in_place() {
+        use alloc_wg::string::String;
+
+        // Sized just large enough for the final string: if `realloc` ever
+        // fell back to `alloc` instead of growing the last block in place,
+        // every intermediate allocation made while `String` grows its
+        // capacity would still be reserved and the arena would have to grow
+        // a second chunk.
+        let bump = BumpAlloc::<Global>::with_capacity_in(64, Global);
+
+        let mut string = String::new_in(&bump);
+        for _ in 0..8 {
+            string.push_str("12345678");
+        }
+        assert_eq!(string.len(), 64);
+        assert_eq!(bump.current.get().data, bump.base.data);
+    }
+
+    #[test]
+    fn bump_realloc_shrink_preserves_leading_bytes() {
+        use alloc_wg::alloc::{AllocRef, NonZeroLayout, ReallocRef};
+
+        let bump = BumpAlloc::<Global>::with_capacity_in(64, Global);
+
+        unsafe {
+            let old_layout = NonZeroLayout::new_unchecked::<[u8; 4]>();
+            let new_layout = NonZeroLayout::new_unchecked::<[u8; 2]>();
+
+            let ptr = (&bump).alloc(old_layout).unwrap();
+            core::ptr::write(ptr.as_ptr() as *mut [u8; 4], [1, 2, 3, 4]);
+
+            let shrunk = (&bump).realloc(ptr, old_layout, new_layout).unwrap();
+            assert_eq!(*(shrunk.as_ptr() as *const [u8; 2]), [1, 2]);
+        }
+    }
+
+    #[test]
+    fn bump_reset_to_checkpoint() {
+        let bump = BumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>() * 2, Global);
+
+        let cp = bump.checkpoint();
+        bump.alloc_t(1.2f32).unwrap();
+
+        unsafe { bump.reset_to(cp) };
+
+        // The slot freed by `reset_to` is available again.
+        let alloc = bump.alloc_t(2.4f32).unwrap();
+        assert_eq!(2.4f32, *alloc);
+    }
+
+    #[test]
+    fn bump_scope_reclaims_on_drop() {
         let bump = BumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>(), Global);
 
-        for idx in 0..=1 {
+        {
+            let scope = bump.scope();
+            scope.alloc_t(1.2f32).unwrap();
+        }
+
+        // The single slot was reclaimed when the scope ended.
+        let alloc = bump.alloc_t(2.4f32).unwrap();
+        assert_eq!(2.4f32, *alloc);
+    }
+
+    #[test]
+    fn bump_scope_frees_grown_chunks() {
+        let bump = BumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>(), Global);
+
+        {
+            let scope = bump.scope();
+            // Forces the arena to grow a second chunk.
+            scope.alloc_t(1.2f32).unwrap();
+            scope.alloc_t(2.4f32).unwrap();
+        }
+
+        // The scope's chunk was freed, leaving only the original one.
+        let alloc = bump.alloc_t(3.6f32).unwrap();
+        assert_eq!(3.6f32, *alloc);
+    }
+
+    #[test]
+    fn bump_reset_to_reuses_retired_chunk_without_double_free() {
+        let bump = BumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>(), Global);
+
+        // Fill the base chunk, then grow past it, so `current` is now a
+        // non-base chunk.
+        bump.alloc_t(0.9f32).unwrap();
+        bump.alloc_t(1.2f32).unwrap();
+
+        // Checkpoint that non-base chunk, then grow past it too — this
+        // retires it onto `chunks` with a footer.
+        let cp = bump.checkpoint();
+        bump.alloc_t(3.4f32).unwrap();
+        bump.alloc_t(5.6f32).unwrap();
+
+        // Restoring the checkpoint makes the retired chunk `current` again.
+        // If its footer were left behind in `chunks`, the chunk would now
+        // be referenced by both `current` and a stale footer.
+        unsafe { bump.reset_to(cp) };
+
+        // Growing past it again must retire it only once more, not push a
+        // second footer for the same chunk onto the stale one above —
+        // otherwise the walk below frees it twice.
+        bump.alloc_t(7.8f32).unwrap();
+        bump.alloc_t(9.1f32).unwrap();
+
+        unsafe { bump.reset_unchecked() };
+
+        let alloc = bump.alloc_t(2.3f32).unwrap();
+        assert_eq!(2.3f32, *alloc);
+    }
+
+    #[test]
+    fn bump_grows_past_initial_capacity() {
+        let bump = BumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>(), Global);
+
+        // The arena only has room for a single `f32`, so a second allocation
+        // must grow into a new chunk rather than returning `AllocErr`.
+        for idx in 0..=4 {
             let stack: f32 = idx as f32;
-            let _alloc = bump.alloc_t(stack).unwrap();
+            let alloc = bump.alloc_t(stack).unwrap();
+            assert_eq!(stack, *alloc);
         }
     }
 
+    #[test]
+    fn bump_alloc_with() {
+        let bump = BumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>(), Global);
+
+        let alloc = bump.alloc_with(|| 1.2f32).unwrap();
+        assert_eq!(1.2f32, *alloc);
+    }
+
+    #[test]
+    fn bump_try_alloc_try_with_ok() {
+        let bump = BumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>(), Global);
+
+        let alloc = bump
+            .try_alloc_try_with(|| Result::<f32, ()>::Ok(1.2f32))
+            .unwrap();
+        assert_eq!(1.2f32, *alloc);
+    }
+
+    #[test]
+    fn bump_try_alloc_try_with_init_err() {
+        use super::AllocOrInitError;
+
+        let bump = BumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>(), Global);
+
+        let err = bump
+            .try_alloc_try_with(|| Result::<f32, &'static str>::Err("failed to init"))
+            .unwrap_err();
+        assert_eq!(err, AllocOrInitError::Init("failed to init"));
+    }
+
+    #[test]
+    fn bump_alloc_slice_copy() {
+        let bump = BumpAlloc::<Global>::with_capacity_in(16, Global);
+
+        let src = [1u32, 2, 3];
+        let slice = bump.alloc_slice_copy(&src).unwrap();
+        assert_eq!(slice, &src);
+    }
+
+    #[test]
+    fn bump_alloc_slice_copy_empty() {
+        let bump = BumpAlloc::<Global>::with_capacity_in(mem::size_of::<u32>(), Global);
+
+        let slice = bump.alloc_slice_copy::<u32>(&[]).unwrap();
+        assert!(slice.is_empty());
+    }
+
+    #[test]
+    fn bump_alloc_str() {
+        let bump = BumpAlloc::<Global>::with_capacity_in(16, Global);
+
+        let s = bump.alloc_str("hello").unwrap();
+        assert_eq!(s, "hello");
+    }
+
+    #[test]
+    fn bump_alloc_slice_fill_with() {
+        let bump = BumpAlloc::<Global>::with_capacity_in(16, Global);
+
+        let slice = bump.alloc_slice_fill_with(4, |idx| idx as u32 * 2).unwrap();
+        assert_eq!(slice, &[0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn bump_from_slice() {
+        let mut buf = [0u8; 8];
+        let bump = BumpAlloc::from_slice(&mut buf);
+
+        let stack = 1.2f32;
+        let alloc = bump.alloc_t(stack).unwrap();
+        assert_eq!(stack, *alloc);
+    }
+
     #[test]
     fn bump_invalid_code() {
         let t = trybuild::TestCases::new();
@@ -0,0 +1,244 @@
+//! A thread-safe counterpart to [`BumpAlloc`](crate::BumpAlloc).
+//!
+//! `BumpAlloc` keeps its bump pointer in a `Cell`, which makes `&BumpAlloc`
+//! `!Sync`. `SyncBumpAlloc` instead keeps the pointer in an `AtomicUsize` and
+//! bumps it with a CAS loop, so multiple threads can allocate out of one
+//! shared arena without a lock. This mirrors static-alloc's sync/unsync
+//! split.
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::num::NonZeroUsize;
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use alloc_wg::alloc::{AllocRef, BuildAllocRef, DeallocRef, Global, NonZeroLayout};
+
+use crate::{AllocErr, BumpAllocErr};
+
+#[derive(Debug)]
+pub struct SyncBumpAlloc<A: DeallocRef = Global> {
+    data: NonNull<u8>,
+    layout: NonZeroLayout,
+    ptr: AtomicUsize,
+    build_alloc: A::BuildAlloc,
+}
+
+// `SyncBumpAlloc` only ever mutates its cursor through `AtomicUsize`
+// operations, so sharing `&SyncBumpAlloc` across threads is sound as long as
+// the allocator it was built from can itself be shared.
+unsafe impl<A: DeallocRef> Sync for SyncBumpAlloc<A> where A::BuildAlloc: Sync {}
+
+impl<A: AllocRef> SyncBumpAlloc<A> {
+    pub fn with_capacity_in(capacity: usize, a: A) -> Self {
+        match Self::try_with_capacity_in(capacity, a) {
+            Ok(bump) => bump,
+            Err(BumpAllocErr::ZeroCapacity) => panic!("zero capacity"),
+            Err(BumpAllocErr::AllocError { .. }) => unreachable!("Infallible allocation"),
+        }
+    }
+
+    pub fn try_with_capacity_in(capacity: usize, a: A) -> Result<Self, BumpAllocErr<A>> {
+        if capacity == 0 {
+            return Err(BumpAllocErr::ZeroCapacity);
+        }
+
+        let layout = unsafe {
+            NonZeroLayout::from_size_align_unchecked(
+                NonZeroUsize::new_unchecked(capacity),
+                NonZeroUsize::new_unchecked(1),
+            )
+        };
+
+        let data = a
+            .alloc(layout)
+            .map_err(|inner| BumpAllocErr::AllocError { layout, inner })?;
+
+        let new_ptr = data.as_ptr() as usize + layout.size().get();
+
+        Ok(Self {
+            data,
+            layout,
+            ptr: AtomicUsize::new(new_ptr),
+            build_alloc: a.get_build_alloc(),
+        })
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_t<T>(&self, val: T) -> Result<&mut T, <&Self as AllocRef>::Error> {
+        assert!(core::mem::size_of::<T>() > 0);
+
+        unsafe {
+            let layout = NonZeroLayout::new_unchecked::<T>();
+
+            let ptr = self.alloc(layout)?;
+            let ptr = ptr.cast::<T>().as_ptr();
+
+            core::ptr::write(ptr, val);
+            Ok(&mut *ptr)
+        }
+    }
+}
+
+impl<A: DeallocRef> SyncBumpAlloc<A> {
+    pub fn reset(&mut self) {
+        let new_ptr = self.data.as_ptr() as usize + self.layout.size().get();
+        self.ptr.store(new_ptr, Ordering::Relaxed);
+    }
+}
+
+impl<A: DeallocRef> Drop for SyncBumpAlloc<A> {
+    fn drop(&mut self) {
+        unsafe {
+            let a = self.build_alloc.build_alloc_ref(self.data, Some(self.layout));
+            a.dealloc(self.data, self.layout);
+        }
+    }
+}
+
+impl<A: DeallocRef> BuildAllocRef for &SyncBumpAlloc<A> {
+    type Ref = Self;
+
+    unsafe fn build_alloc_ref(
+        &self,
+        _ptr: NonNull<u8>,
+        _layout: Option<NonZeroLayout>,
+    ) -> Self::Ref {
+        self
+    }
+}
+
+impl<A: DeallocRef> DeallocRef for &SyncBumpAlloc<A> {
+    type BuildAlloc = Self;
+
+    fn get_build_alloc(&self) -> Self::BuildAlloc {
+        self
+    }
+
+    unsafe fn dealloc(&self, _ptr: NonNull<u8>, _layout: NonZeroLayout) {}
+}
+
+impl<A: DeallocRef> AllocRef for &SyncBumpAlloc<A> {
+    type Error = AllocErr;
+
+    fn alloc(&self, layout: NonZeroLayout) -> Result<NonNull<u8>, Self::Error> {
+        let start = self.data.as_ptr() as usize;
+        let mut cur = self.ptr.load(Ordering::Relaxed);
+
+        loop {
+            let new_ptr = cur.checked_sub(layout.size().get()).ok_or(AllocErr)?;
+
+            // Round down to the requested alignment.
+            let new_ptr = new_ptr & !(layout.align().get() - 1);
+
+            if new_ptr < start {
+                // Not enough capacity.
+                return Err(AllocErr);
+            }
+
+            match self.ptr.compare_exchange_weak(
+                cur,
+                new_ptr,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Ok(unsafe { NonNull::new_unchecked(new_ptr as *mut u8) }),
+                Err(observed) => cur = observed,
+            }
+        }
+    }
+}
+
+// Only `SyncBumpAlloc` implements `GlobalAlloc`, since `#[global_allocator]`
+// requires the allocator type to be `Sync` and `BumpAlloc`'s `Cell`-based
+// cursor isn't.
+unsafe impl<A: DeallocRef> GlobalAlloc for SyncBumpAlloc<A>
+where
+    A::BuildAlloc: Sync,
+{
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.size() == 0 {
+            return layout.align() as *mut u8;
+        }
+
+        let layout = match NonZeroLayout::from_size_align(layout.size(), layout.align()) {
+            Some(layout) => layout,
+            None => return ptr::null_mut(),
+        };
+
+        match AllocRef::alloc(&self, layout) {
+            Ok(ptr) => ptr.as_ptr(),
+            // The `GlobalAlloc` contract wants a null pointer on exhaustion,
+            // not an `AllocErr`.
+            Err(_) => ptr::null_mut(),
+        }
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SyncBumpAlloc;
+    use alloc_wg::alloc::Global;
+    use core::mem;
+
+    #[test]
+    fn sync_bump_alloc() {
+        let bump = SyncBumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>() * 2, Global);
+
+        let stack_a = 1.2f32;
+        let alloc_a = bump.alloc_t(stack_a.clone()).unwrap();
+        assert_eq!(stack_a, *alloc_a);
+
+        let stack_b = 2.4f32;
+        let alloc_b = bump.alloc_t(stack_b.clone()).unwrap();
+        assert_eq!(stack_b, *alloc_b);
+    }
+
+    #[test]
+    fn sync_bump_alloc_across_threads() {
+        extern crate std;
+        use std::thread;
+
+        let bump = SyncBumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>() * 8, Global);
+
+        thread::scope(|scope| {
+            for idx in 0..8 {
+                let bump = &bump;
+                scope.spawn(move || {
+                    let stack = idx as f32;
+                    let alloc = bump.alloc_t(stack).unwrap();
+                    assert_eq!(stack, *alloc);
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn sync_bump_global_alloc() {
+        use core::alloc::{GlobalAlloc, Layout};
+
+        let bump = SyncBumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>(), Global);
+
+        let layout = Layout::new::<f32>();
+        let ptr = unsafe { GlobalAlloc::alloc(&bump, layout) };
+        assert!(!ptr.is_null());
+
+        // The arena is now exhausted.
+        let ptr = unsafe { GlobalAlloc::alloc(&bump, layout) };
+        assert!(ptr.is_null());
+    }
+
+    #[test]
+    fn sync_bump_reset() {
+        let mut bump = SyncBumpAlloc::<Global>::with_capacity_in(mem::size_of::<f32>(), Global);
+
+        for idx in 0..=2 {
+            bump.reset();
+
+            let new_stack: f32 = idx as f32;
+            let new_alloc = bump.alloc_t(new_stack.clone()).unwrap();
+            assert_eq!(new_stack, *new_alloc);
+        }
+    }
+}